@@ -21,7 +21,7 @@ use ascii::AsciiChar;
 use core::convert::From;
 use core::convert::TryFrom;
 use core::fmt;
-use embedded_hal::digital::OutputPinMatrix;
+use embedded_hal::digital::{OutputPin, OutputPinMatrix};
 use ignore_result::Ignore;
 
 /// An Alphanumeric LED module
@@ -43,40 +43,151 @@ where
     P: OutputPinMatrix<2, 8>,
 {
     type Input = Character;
+    type Error = P::Error;
 
-    fn set(&mut self, c: Character) {
+    fn set(&mut self, c: Character) -> Result<(), P::Error> {
         let glyph =
             font::MAP[c.base as usize & 0x7F] | if c.point { font::MAP['.' as usize] } else { 0 };
-        self.pins
-            .set_column(
-                0,
-                &[
-                    (glyph >> 0) & 0x01 == 0x01,
-                    (glyph >> 1) & 0x01 == 0x01,
-                    (glyph >> 2) & 0x01 == 0x01,
-                    (glyph >> 3) & 0x01 == 0x01,
-                    (glyph >> 4) & 0x01 == 0x01,
-                    (glyph >> 5) & 0x01 == 0x01,
-                    (glyph >> 6) & 0x01 == 0x01,
-                    (glyph >> 7) & 0x01 == 0x01,
-                ],
-            )
-            .ignore();
-        self.pins
-            .set_column(
-                1,
-                &[
-                    (glyph >> 8) & 0x01 == 0x01,
-                    (glyph >> 9) & 0x01 == 0x01,
-                    (glyph >> 10) & 0x01 == 0x01,
-                    (glyph >> 11) & 0x01 == 0x01,
-                    (glyph >> 12) & 0x01 == 0x01,
-                    (glyph >> 13) & 0x01 == 0x01,
-                    (glyph >> 14) & 0x01 == 0x01,
-                    (glyph >> 15) & 0x01 == 0x01,
-                ],
-            )
-            .ignore();
+        self.pins.set_column(
+            0,
+            &[
+                (glyph >> 0) & 0x01 == 0x01,
+                (glyph >> 1) & 0x01 == 0x01,
+                (glyph >> 2) & 0x01 == 0x01,
+                (glyph >> 3) & 0x01 == 0x01,
+                (glyph >> 4) & 0x01 == 0x01,
+                (glyph >> 5) & 0x01 == 0x01,
+                (glyph >> 6) & 0x01 == 0x01,
+                (glyph >> 7) & 0x01 == 0x01,
+            ],
+        )?;
+        self.pins.set_column(
+            1,
+            &[
+                (glyph >> 8) & 0x01 == 0x01,
+                (glyph >> 9) & 0x01 == 0x01,
+                (glyph >> 10) & 0x01 == 0x01,
+                (glyph >> 11) & 0x01 == 0x01,
+                (glyph >> 12) & 0x01 == 0x01,
+                (glyph >> 13) & 0x01 == 0x01,
+                (glyph >> 14) & 0x01 == 0x01,
+                (glyph >> 15) & 0x01 == 0x01,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+/// A multiplexed display of `N` alphanumeric digits sharing a single column driver.
+///
+/// `Display` owns a fixed-width buffer of [`Character`]s and one enable pin per digit, and scans
+/// them round-robin: each call to [`Self::refresh`] disables the currently-lit digit, writes the
+/// next digit's glyph to the shared columns, and enables that digit's common line. Driven fast
+/// enough, persistence of vision renders the whole buffer as a single string; call `refresh` at
+/// least `N * 100` Hz (100 Hz per digit) to avoid visible flicker.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::digital::{OutputPin, OutputPinMatrix};
+///
+/// # struct Pins {}
+/// #
+/// # impl OutputPinMatrix<2, 8> for Pins {
+/// #     type Error = ();
+/// #
+/// #     fn set_column(&mut self, _column: usize, _rows: &[bool; 8]) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # struct Enable {}
+/// #
+/// # impl OutputPin for Enable {
+/// #     type Error = ();
+/// #
+/// #     fn set_low(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// #
+/// #     fn set_high(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # let mut pins = Pins {};
+/// #
+/// use led::alphanumeric::Display;
+///
+/// let mut display: Display<_, _, 4> =
+///     Display::new(&mut pins, [Enable {}, Enable {}, Enable {}, Enable {}]);
+///
+/// display.write_str("ABCD").unwrap();
+///
+/// for _ in 0..4 {
+///     display.refresh();
+/// }
+/// ```
+pub struct Display<'a, P, E, const N: usize> {
+    led: LED<'a, P>,
+    enables: [E; N],
+    buffer: [Character; N],
+    active: usize,
+}
+
+impl<'a, P, E, const N: usize> Display<'a, P, E, N>
+where
+    P: OutputPinMatrix<2, 8>,
+    E: OutputPin,
+{
+    /// Creates a new display given the shared column driver and one enable pin per digit, all
+    /// digits initially blank.
+    pub fn new(pins: &'a mut P, enables: [E; N]) -> Display<'a, P, E, N> {
+        Display {
+            led: LED::new(pins),
+            enables,
+            buffer: [Character {
+                base: b' ',
+                point: false,
+            }; N],
+            active: 0,
+        }
+    }
+
+    /// Writes `s` into the display buffer, truncating it or padding it with spaces to fit `N`
+    /// characters.
+    ///
+    /// Returns [`Error::NonAscii`] and leaves the buffer unchanged if `s` contains a non-ASCII
+    /// character.
+    pub fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        if !s.is_ascii() {
+            return Err(Error::NonAscii);
+        }
+
+        let mut bytes = s.bytes();
+        for slot in self.buffer.iter_mut() {
+            *slot = Character {
+                base: bytes.next().unwrap_or(b' '),
+                point: false,
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Advances the multiplex scan by one digit: disables the currently-lit digit, writes the
+    /// next digit's glyph to the shared columns, and enables that digit.
+    pub fn refresh(&mut self) {
+        if N == 0 {
+            return;
+        }
+
+        self.enables[self.active].set_low().ignore();
+
+        self.active = (self.active + 1) % N;
+
+        crate::LED::set(&mut self.led, self.buffer[self.active]).ignore();
+        self.enables[self.active].set_high().ignore();
     }
 }
 
@@ -90,6 +201,7 @@ impl fmt::Debug for Error {
     }
 }
 
+#[derive(Clone, Copy)]
 pub struct Character {
     base: u8,
     point: bool,
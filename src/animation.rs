@@ -0,0 +1,273 @@
+// Copyright 2022 Alex Crawford
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Non-blocking blink/breathe/sequence animations for LED outputs.
+//!
+//! [`Animated`] wraps an LED together with a [`Pattern`] and a phase accumulator. Calling
+//! [`Animated::poll`] with the time elapsed since the last call advances the accumulator and
+//! writes the LED's state whenever the pattern crosses a boundary, so callers don't need to
+//! drive the animation from a blocking loop or interrupt themselves; a timer tick is enough.
+//!
+//! # Examples
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use embedded_hal::digital::v2::OutputPin;
+//! #
+//! # struct Pin {}
+//! #
+//! # impl OutputPin for Pin {
+//! #     type Error = ();
+//! #     fn set_low(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! #     fn set_high(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! #
+//! # let r = Pin{};
+//! # let g = Pin{};
+//! # let b = Pin{};
+//! #
+//! use led::animation::{Animated, Pattern};
+//! use led::rgb::{Color, CommonAnodeLed};
+//!
+//! let mut led = Animated::new(
+//!     CommonAnodeLed::new(r, g, b),
+//!     Color::Red,
+//!     Pattern::Blink {
+//!         on: Duration::from_millis(500),
+//!         off: Duration::from_millis(500),
+//!     },
+//! );
+//!
+//! led.poll(Duration::from_millis(10));
+//! ```
+
+use core::time::Duration;
+
+/// An LED output that can be driven by [`Animated`].
+///
+/// This is implemented directly for [`crate::monocolor::LED`], [`crate::rgb::Led`], and
+/// [`crate::rgb::PwmLed`] (not for [`crate::alphanumeric::LED`]) rather than blanket implemented
+/// over [`crate::LED`] and [`crate::Led`], since both traits could in principle be
+/// implemented by the same type and a blanket impl for each would conflict.
+pub trait Settable {
+    /// The state this LED is set to, e.g. [`crate::rgb::Color`] or [`crate::monocolor::State`].
+    type State: Copy + PartialEq;
+
+    /// The state that turns this LED fully off.
+    const OFF: Self::State;
+
+    /// Sets the LED to the given state.
+    fn set(&mut self, state: Self::State);
+
+    /// Scales `on` to the given intensity (0 is off, 255 is unscaled), for LEDs capable of
+    /// variable brightness. Returns `None` for binary on/off LEDs, which have no way to
+    /// represent anything between off and `on`.
+    fn scale(on: Self::State, intensity: u8) -> Option<Self::State> {
+        let _ = (on, intensity);
+        None
+    }
+}
+
+impl<C, P> Settable for crate::monocolor::LED<C, P>
+where
+    crate::monocolor::LED<C, P>: crate::LED<Input = crate::monocolor::State>,
+{
+    type State = crate::monocolor::State;
+
+    const OFF: Self::State = crate::monocolor::State::Off;
+
+    fn set(&mut self, state: Self::State) {
+        let _ = crate::LED::set(self, state);
+    }
+}
+
+impl<C, R, G, B> Settable for crate::rgb::Led<C, R, G, B>
+where
+    crate::rgb::Led<C, R, G, B>: crate::Led<State = crate::rgb::Color>,
+{
+    type State = crate::rgb::Color;
+
+    const OFF: Self::State = crate::rgb::Color::Black;
+
+    fn set(&mut self, state: Self::State) {
+        let _ = crate::Led::set(self, state);
+    }
+}
+
+impl<C, R, G, B> Settable for crate::rgb::PwmLed<C, R, G, B>
+where
+    crate::rgb::PwmLed<C, R, G, B>: crate::Led<State = crate::rgb::Color>,
+{
+    type State = crate::rgb::Color;
+
+    const OFF: Self::State = crate::rgb::Color::Black;
+
+    fn set(&mut self, state: Self::State) {
+        let _ = crate::Led::set(self, state);
+    }
+
+    fn scale(on: Self::State, intensity: u8) -> Option<Self::State> {
+        Some(on.scale(intensity))
+    }
+}
+
+/// An animation pattern.
+pub enum Pattern<S> {
+    /// The LED stays on its configured color indefinitely.
+    Solid,
+    /// The LED alternates between its configured color and off.
+    Blink {
+        /// How long the LED stays on.
+        on: Duration,
+        /// How long the LED stays off.
+        off: Duration,
+    },
+    /// The LED's brightness rises and falls like a breath. LEDs with variable brightness follow
+    /// a triangle wave over `period`; binary on/off LEDs fall back to a 50% duty approximation.
+    Breathe {
+        /// The duration of one full breathe cycle.
+        period: Duration,
+    },
+    /// The LED cycles through a sequence of colors, each held for its given duration, wrapping
+    /// back to the start once the sequence ends.
+    Sequence(&'static [(S, Duration)]),
+}
+
+/// A non-blocking animation wrapper around an LED.
+///
+/// `Animated` holds the inner LED, the pattern's base color, the active [`Pattern`], and a phase
+/// accumulator. It keeps all animation timing out of the caller's hot loop: call [`Self::poll`]
+/// with the elapsed time on every tick and the LED is updated only when the pattern crosses a
+/// boundary.
+pub struct Animated<L: Settable> {
+    led: L,
+    on: L::State,
+    pattern: Pattern<L::State>,
+    phase: Duration,
+    last: L::State,
+}
+
+impl<L: Settable> Animated<L> {
+    /// Wraps `led`, animating between `on` and off according to `pattern`.
+    pub fn new(mut led: L, on: L::State, pattern: Pattern<L::State>) -> Animated<L> {
+        let initial = match pattern {
+            Pattern::Solid => on,
+            _ => L::OFF,
+        };
+        led.set(initial);
+        Animated {
+            led,
+            on,
+            pattern,
+            phase: Duration::ZERO,
+            last: initial,
+        }
+    }
+
+    /// Switches to a new pattern, restarting the phase accumulator from zero.
+    pub fn set_pattern(&mut self, pattern: Pattern<L::State>) {
+        self.pattern = pattern;
+        self.phase = Duration::ZERO;
+    }
+
+    /// Advances the animation by `elapsed` and writes the LED's state if a pattern boundary was
+    /// crossed.
+    pub fn poll(&mut self, elapsed: Duration) {
+        self.phase += elapsed;
+
+        let desired = match &self.pattern {
+            Pattern::Solid => Some(self.on),
+            Pattern::Blink { on, off } => {
+                let cycle = *on + *off;
+                if cycle.is_zero() {
+                    None
+                } else {
+                    self.phase = wrap(self.phase, cycle);
+                    Some(if self.phase < *on { self.on } else { L::OFF })
+                }
+            }
+            Pattern::Breathe { period } => {
+                let period = *period;
+                if period.is_zero() {
+                    None
+                } else {
+                    self.phase = wrap(self.phase, period);
+                    let half = period / 2;
+                    let level = triangle_wave(self.phase, half);
+                    Some(match L::scale(self.on, level) {
+                        Some(state) => state,
+                        None => {
+                            if self.phase < half {
+                                self.on
+                            } else {
+                                L::OFF
+                            }
+                        }
+                    })
+                }
+            }
+            Pattern::Sequence(steps) => {
+                if steps.is_empty() {
+                    None
+                } else {
+                    let total: Duration = steps.iter().map(|(_, duration)| *duration).sum();
+                    if total.is_zero() {
+                        None
+                    } else {
+                        self.phase = wrap(self.phase, total);
+
+                        let mut boundary = Duration::ZERO;
+                        steps.iter().find_map(|(state, duration)| {
+                            boundary += *duration;
+                            (self.phase < boundary).then_some(*state)
+                        })
+                    }
+                }
+            }
+        };
+
+        // Only re-drive the pins when the computed state actually changed, so a pattern that
+        // holds steady between ticks (e.g. `Solid`, or `Blink` between its on/off transitions)
+        // doesn't re-issue redundant pin writes every poll.
+        if let Some(state) = desired {
+            if state != self.last {
+                self.led.set(state);
+                self.last = state;
+            }
+        }
+    }
+}
+
+/// Wraps `phase` into `0..period`.
+fn wrap(phase: Duration, period: Duration) -> Duration {
+    Duration::from_nanos((phase.as_nanos() % period.as_nanos()) as u64)
+}
+
+/// Computes a 0-255 triangle wave value for `phase` within a breathe cycle whose rising and
+/// falling halves each last `half`.
+fn triangle_wave(phase: Duration, half: Duration) -> u8 {
+    if half.is_zero() {
+        return 0;
+    }
+    if phase < half {
+        (phase.as_nanos() * 255 / half.as_nanos()) as u8
+    } else {
+        let falling = phase - half;
+        (255 - falling.as_nanos() * 255 / half.as_nanos()) as u8
+    }
+}
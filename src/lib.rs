@@ -12,77 +12,63 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use embedded_hal::digital::OutputPin;
+pub mod alphanumeric;
+pub mod animation;
+pub mod monocolor;
+pub mod polarity;
+pub mod rgb;
 
-pub enum Color {
-    Red,
-    Green,
-    Blue,
-    Yellow,
-    Cyan,
-    Magenta,
-    White,
-    Black,
+/// A single-input LED output; implemented by [`monocolor::LED`], [`alphanumeric::LED`], and, as
+/// an on/off output, by every [`Brightness`] LED.
+pub trait LED {
+    /// The state this LED is set to, e.g. [`monocolor::State`].
+    type Input;
+
+    /// The error returned when the underlying pins fail.
+    type Error;
+
+    /// Sets the LED to the given state.
+    fn set(&mut self, input: Self::Input) -> Result<(), Self::Error>;
 }
 
-pub struct RGB<R, G, B> {
-    red: R,
-    green: G,
-    blue: B,
+/// An RGB LED output; implemented by [`rgb::Led`] and [`rgb::PwmLed`].
+pub trait Led {
+    /// The state this LED is set to, e.g. [`rgb::Color`].
+    type State;
+
+    /// The error returned when the underlying pins fail.
+    type Error;
+
+    /// Sets the LED to the given state.
+    fn set(&mut self, state: Self::State) -> Result<(), Self::Error>;
 }
 
-impl<R, G, B> RGB<R, G, B>
+/// Brightness control for LEDs capable of variable intensity (i.e. PWM-backed), mirroring the
+/// Linux kernel LED class's `brightness_set`/`brightness_get`.
+pub trait Brightness {
+    /// The error returned when the underlying pins fail.
+    type Error;
+
+    /// Sets the brightness, where `0` is fully off and `255` is fully on.
+    fn set_brightness(&mut self, brightness: u8) -> Result<(), Self::Error>;
+
+    /// Returns the brightness last set with [`Self::set_brightness`].
+    fn get_brightness(&self) -> u8;
+}
+
+impl<T> LED for T
 where
-    R: OutputPin,
-    G: OutputPin,
-    B: OutputPin,
+    T: Brightness,
 {
-    pub fn new(red: R, green: G, blue: B) -> RGB<R, G, B> {
-        RGB { red, green, blue }
-    }
+    type Input = monocolor::State;
+    type Error = T::Error;
 
-    pub fn set_color(&mut self, color: Color) {
-        match color {
-            Color::Red => {
-                self.red.set_low();
-                self.green.set_high();
-                self.blue.set_high();
-            }
-            Color::Green => {
-                self.red.set_high();
-                self.green.set_low();
-                self.blue.set_high();
-            }
-            Color::Blue => {
-                self.red.set_high();
-                self.green.set_high();
-                self.blue.set_low();
-            }
-            Color::Yellow => {
-                self.red.set_low();
-                self.green.set_low();
-                self.blue.set_high();
-            }
-            Color::Cyan => {
-                self.red.set_high();
-                self.green.set_low();
-                self.blue.set_low();
-            }
-            Color::Magenta => {
-                self.red.set_low();
-                self.green.set_high();
-                self.blue.set_low();
-            }
-            Color::White => {
-                self.red.set_low();
-                self.green.set_low();
-                self.blue.set_low();
-            }
-            Color::Black => {
-                self.red.set_high();
-                self.green.set_high();
-                self.blue.set_high();
-            }
-        }
+    /// Maps [`monocolor::State::Off`]/[`monocolor::State::On`] to `0`/`255` brightness, so any
+    /// `Brightness` LED gets a consistent, `Result`-returning on/off `LED` surface for free.
+    fn set(&mut self, input: Self::Input) -> Result<(), Self::Error> {
+        self.set_brightness(match input {
+            monocolor::State::Off => 0,
+            monocolor::State::On => 255,
+        })
     }
 }
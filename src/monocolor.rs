@@ -0,0 +1,174 @@
+// Copyright 2021 Alex Crawford
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Monocolor LEDs
+//!
+//! Various types of single-pin, binary on/off LEDs.
+//!
+//! # Examples
+//!
+//! ```
+//! # use embedded_hal::digital::v2::OutputPin;
+//! #
+//! # struct Pin {}
+//! #
+//! # impl OutputPin for Pin {
+//! #     type Error = ();
+//! #     fn set_low(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! #     fn set_high(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! #
+//! # let p = Pin{};
+//! #
+//! use led::LED;
+//! use led::monocolor::{CommonAnodeLED, State};
+//!
+//! let mut led = CommonAnodeLED::new(p);
+//!
+//! led.set(State::On).unwrap();
+//! ```
+//!
+//! ```
+//! # use embedded_hal::digital::v2::OutputPin;
+//! #
+//! # struct Pin {}
+//! #
+//! # impl OutputPin for Pin {
+//! #     type Error = ();
+//! #     fn set_low(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! #     fn set_high(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! #
+//! # let p1 = Pin{};
+//! # let p2 = Pin{};
+//! #
+//! use led::LED;
+//! use led::monocolor::{CommonAnodeLED, CommonCathodeLED, Monocolor, State};
+//!
+//! let mut leds: [&mut dyn Monocolor<Error = ()>; 2] = [
+//!     &mut CommonAnodeLED::new(p1),
+//!     &mut CommonCathodeLED::new(p2),
+//! ];
+//!
+//! for led in leds.iter_mut() {
+//!     led.set(State::On).unwrap();
+//! }
+//! ```
+
+use core::marker::PhantomData;
+use embedded_hal::digital::v2::OutputPin;
+
+pub use crate::polarity::{Common, CommonAnode, CommonCathode};
+
+/// A common anode LED.
+pub type CommonAnodeLED<P> = LED<CommonAnode, P>;
+
+/// A common cathode LED.
+pub type CommonCathodeLED<P> = LED<CommonCathode, P>;
+
+/// A monocolor LED; either common anode or common cathode.
+///
+/// # Examples
+///
+/// ```
+/// # use embedded_hal::digital::v2::OutputPin;
+/// #
+/// # struct Pin {}
+/// #
+/// # impl OutputPin for Pin {
+/// #     type Error = ();
+/// #     fn set_low(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// #     fn set_high(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # let p1 = Pin{};
+/// # let p2 = Pin{};
+/// #
+/// use led::monocolor::{CommonAnodeLED, CommonCathodeLED, Monocolor, State};
+///
+/// let mut leds: [&mut dyn Monocolor<Error = ()>; 2] = [
+///     &mut CommonAnodeLED::new(p1),
+///     &mut CommonCathodeLED::new(p2),
+/// ];
+///
+/// for led in leds.iter_mut() {
+///     led.set(State::On).unwrap();
+/// }
+/// ```
+pub trait Monocolor: crate::LED<Input = State> {}
+impl<L> Monocolor for L where L: crate::LED<Input = State> {}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum State {
+    On,
+    Off,
+}
+
+/// A monocolor LED
+///
+/// The monocolor LED is represented by a single owned instance of
+/// `embedded_hal::digital::v2::OutputPin` and a polarity (common anode or common cathode).
+/// Because the output is binary, the LED can only be fully on or fully off.
+pub struct LED<C, P> {
+    common: PhantomData<C>,
+    pin: P,
+}
+
+impl<C, P> LED<C, P>
+where
+    C: Common,
+    P: OutputPin,
+{
+    /// Creates a new monocolor LED given a single GPIO.
+    pub fn new(pin: P) -> LED<C, P> {
+        LED {
+            common: PhantomData,
+            pin,
+        }
+    }
+
+    /// Sets the monocolor LED to the specified state.
+    fn set(&mut self, state: State) -> Result<(), P::Error> {
+        match state {
+            State::Off => C::disable(&mut self.pin),
+            State::On => C::enable(&mut self.pin),
+        }
+    }
+}
+
+impl<C, P> crate::LED for LED<C, P>
+where
+    C: Common,
+    P: OutputPin,
+{
+    type Input = State;
+    type Error = P::Error;
+
+    /// Sets the monocolor LED to the specified state.
+    fn set(&mut self, state: State) -> Result<(), P::Error> {
+        self.set(state)
+    }
+}
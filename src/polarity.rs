@@ -0,0 +1,115 @@
+// Copyright 2023 Alex Crawford
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Polarity shared by the crate's monocolor and RGB LED types.
+//!
+//! An LED's polarity is whether driving its pin low or high turns it on: common anode wiring
+//! turns on when pulled low, common cathode wiring turns on when pulled high. [`monocolor`](crate::monocolor)
+//! and [`rgb`](crate::rgb) both build on the [`Common`] marker types here instead of redeclaring
+//! their own.
+
+use embedded_hal::digital::v2::OutputPin;
+
+/// The polarity of an LED; either anode or cathode.
+pub trait Common {
+    /// Enables the pin output.
+    fn enable<P: OutputPin>(pin: &mut P) -> Result<(), P::Error>;
+
+    /// Disables the pin output.
+    fn disable<P: OutputPin>(pin: &mut P) -> Result<(), P::Error>;
+}
+
+/// A marker type that represents a common anode connection.
+///
+/// # Examples
+///
+/// ```
+/// # use embedded_hal::digital::v2::OutputPin;
+/// #
+/// # struct Pin {}
+/// #
+/// # impl OutputPin for Pin {
+/// #     type Error = ();
+/// #     fn set_low(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// #     fn set_high(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # let r = Pin{};
+/// # let g = Pin{};
+/// # let b = Pin{};
+/// #
+/// use led::polarity::CommonAnode;
+/// use led::rgb::Led;
+///
+/// let led: Led<CommonAnode, _, _, _> = Led::new(r, g, b);
+/// ```
+pub struct CommonAnode {
+    private: core::marker::PhantomData<()>,
+}
+
+/// A marker type that represents a common cathode connection.
+///
+/// # Examples
+///
+/// ```
+/// # use embedded_hal::digital::v2::OutputPin;
+/// #
+/// # struct Pin {}
+/// #
+/// # impl OutputPin for Pin {
+/// #     type Error = ();
+/// #     fn set_low(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// #     fn set_high(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # let r = Pin{};
+/// # let g = Pin{};
+/// # let b = Pin{};
+/// #
+/// use led::polarity::CommonCathode;
+/// use led::rgb::Led;
+///
+/// let led: Led<CommonCathode, _, _, _> = Led::new(r, g, b);
+/// ```
+pub struct CommonCathode {
+    private: core::marker::PhantomData<()>,
+}
+
+impl Common for CommonAnode {
+    fn enable<P: OutputPin>(pin: &mut P) -> Result<(), P::Error> {
+        pin.set_low()
+    }
+
+    fn disable<P: OutputPin>(pin: &mut P) -> Result<(), P::Error> {
+        pin.set_high()
+    }
+}
+
+impl Common for CommonCathode {
+    fn enable<P: OutputPin>(pin: &mut P) -> Result<(), P::Error> {
+        pin.set_high()
+    }
+
+    fn disable<P: OutputPin>(pin: &mut P) -> Result<(), P::Error> {
+        pin.set_low()
+    }
+}
@@ -19,13 +19,18 @@
 //! # Examples
 //!
 //! ```
-//! # use embedded_hal::digital::OutputPin;
+//! # use embedded_hal::digital::v2::OutputPin;
 //! #
 //! # struct Pin {}
 //! #
 //! # impl OutputPin for Pin {
-//! #     fn set_low(&mut self){}
-//! #     fn set_high(&mut self){}
+//! #     type Error = ();
+//! #     fn set_low(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! #     fn set_high(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
 //! # }
 //! #
 //! # let r = Pin{};
@@ -37,17 +42,22 @@
 //!
 //! let mut led = CommonAnodeLed::new(r, g, b);
 //!
-//! led.set(Color::Green);
+//! led.set(Color::Green).unwrap();
 //! ```
 //!
 //! ```
-//! # use embedded_hal::digital::OutputPin;
+//! # use embedded_hal::digital::v2::OutputPin;
 //! #
 //! # struct Pin {}
 //! #
 //! # impl OutputPin for Pin {
-//! #     fn set_low(&mut self){}
-//! #     fn set_high(&mut self){}
+//! #     type Error = ();
+//! #     fn set_low(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! #     fn set_high(&mut self) -> Result<(), ()> {
+//! #         Ok(())
+//! #     }
 //! # }
 //! #
 //! # let r1 = Pin{};
@@ -60,21 +70,25 @@
 //! use led::Led;
 //! use led::rgb::{Color, CommonAnodeLed, CommonCathodeLed, Rgb};
 //!
-//! let mut leds: [&mut dyn Rgb; 2] = [
+//! let mut leds: [&mut dyn Rgb<Error = ()>; 2] = [
 //!     &mut CommonAnodeLed::new(r1, g1, b1),
 //!     &mut CommonCathodeLed::new(r2, g2, b2),
 //! ];
 //!
 //! for led in leds.iter_mut() {
-//!     led.set(Color::Red);
+//!     led.set(Color::Red).unwrap();
 //! }
 //! ```
 
 use core::marker::PhantomData;
-use embedded_hal::digital::OutputPin;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal::PwmPin;
+
+pub use crate::polarity::{CommonAnode, CommonCathode};
 
 /// The set of primary colors and secondary colors that can be created by an RGB LED along with
-/// black and white.
+/// black and white, plus arbitrary colors for [`PwmLed`].
+#[derive(Clone, Copy, PartialEq)]
 pub enum Color {
     Red,
     Green,
@@ -84,6 +98,36 @@ pub enum Color {
     Magenta,
     White,
     Black,
+    /// An arbitrary color given as its red, green, and blue channel intensities.
+    Rgb(u8, u8, u8),
+    /// An arbitrary color given as a 24-bit `0xRRGGBB` hex value.
+    Hex(u32),
+}
+
+impl Color {
+    /// Splits the color into its red, green, and blue 8-bit channel intensities.
+    pub fn split(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Red => (255, 0, 0),
+            Color::Green => (0, 255, 0),
+            Color::Blue => (0, 0, 255),
+            Color::Yellow => (255, 255, 0),
+            Color::Cyan => (0, 255, 255),
+            Color::Magenta => (255, 0, 255),
+            Color::White => (255, 255, 255),
+            Color::Black => (0, 0, 0),
+            Color::Rgb(red, green, blue) => (red, green, blue),
+            Color::Hex(hex) => ((hex >> 16) as u8, (hex >> 8) as u8, hex as u8),
+        }
+    }
+
+    /// Scales each channel's intensity by `brightness`, where `0` is fully off and `255` leaves
+    /// the color unscaled.
+    pub fn scale(&self, brightness: u8) -> Color {
+        let (red, green, blue) = self.split();
+        let scale = |channel: u8| ((u16::from(channel) * u16::from(brightness)) / 0xFF) as u8;
+        Color::Rgb(scale(red), scale(green), scale(blue))
+    }
 }
 
 /// A common anode LED.
@@ -97,13 +141,18 @@ pub type CommonCathodeLed<R, G, B> = Led<CommonCathode, R, G, B>;
 /// # Examples
 ///
 /// ```
-/// # use embedded_hal::digital::OutputPin;
+/// # use embedded_hal::digital::v2::OutputPin;
 /// #
 /// # struct Pin {}
 /// #
 /// # impl OutputPin for Pin {
-/// #     fn set_low(&mut self){}
-/// #     fn set_high(&mut self){}
+/// #     type Error = ();
+/// #     fn set_low(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// #     fn set_high(&mut self) -> Result<(), ()> {
+/// #         Ok(())
+/// #     }
 /// # }
 /// #
 /// # let r1 = Pin{};
@@ -115,13 +164,13 @@ pub type CommonCathodeLed<R, G, B> = Led<CommonCathode, R, G, B>;
 /// #
 /// use led::rgb::{Color, CommonAnodeLed, CommonCathodeLed, Rgb};
 ///
-/// let mut leds: [&mut dyn Rgb; 2] = [
+/// let mut leds: [&mut dyn Rgb<Error = ()>; 2] = [
 ///     &mut CommonAnodeLed::new(r1, g1, b1),
 ///     &mut CommonCathodeLed::new(r2, g2, b2),
 /// ];
 ///
 /// for led in leds.iter_mut() {
-///     led.set(Color::Red);
+///     led.set(Color::Red).unwrap();
 /// }
 /// ```
 pub trait Rgb: crate::Led<State = Color> {}
@@ -129,27 +178,87 @@ impl<L> Rgb for L where L: crate::Led<State = Color> {}
 
 /// An RGB LED
 ///
-/// The RGB LED is represented by three owned instances of `embedded_hal::digital::OutputPin` and a
-/// polarity (common anode or common cathode). Because the outputs are binary, only eight colors
-/// can be presented: primary colors, secondary colors, white, and black.
+/// The RGB LED is represented by three owned instances of `embedded_hal::digital::v2::OutputPin`
+/// and a polarity (common anode or common cathode). Because the outputs are binary, only eight
+/// colors can be presented: primary colors, secondary colors, white, and black.
+///
+/// Polarity is normally baked into the type via `C`, but [`Led::with_polarity`] can override it
+/// with a runtime [`Polarity`] instead; see that constructor for why you'd want to.
 pub struct Led<C, R, G, B> {
     common: PhantomData<C>,
+    polarity: Option<Polarity>,
     red: R,
     green: G,
     blue: B,
 }
 
-impl<C, R, G, B> Led<C, R, G, B>
+impl<C, R, G, B, E> Led<C, R, G, B>
 where
     C: Common,
-    R: OutputPin,
-    G: OutputPin,
-    B: OutputPin,
+    R: OutputPin<Error = E>,
+    G: OutputPin<Error = E>,
+    B: OutputPin<Error = E>,
 {
-    /// Creates a new RGB LED given three GPIOs.
+    /// Creates a new RGB LED given three GPIOs, with polarity fixed by `C`.
     pub fn new(red: R, green: G, blue: B) -> Led<C, R, G, B> {
         Led {
             common: PhantomData,
+            polarity: None,
+            red,
+            green,
+            blue,
+        }
+    }
+
+    /// Creates a new RGB LED given three GPIOs, with polarity chosen at runtime instead of by
+    /// `C`.
+    ///
+    /// This lets LEDs wired with different polarities share a single concrete type -- e.g.
+    /// `[Led<CommonAnode, P, P, P>; N]` -- and coexist in an array without `&mut dyn Rgb`, since
+    /// the type parameter `C` no longer needs to vary to express the wiring difference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_hal::digital::v2::OutputPin;
+    /// #
+    /// # struct Pin {}
+    /// #
+    /// # impl OutputPin for Pin {
+    /// #     type Error = ();
+    /// #     fn set_low(&mut self) -> Result<(), ()> {
+    /// #         Ok(())
+    /// #     }
+    /// #     fn set_high(&mut self) -> Result<(), ()> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// # let r1 = Pin{};
+    /// # let g1 = Pin{};
+    /// # let b1 = Pin{};
+    /// # let r2 = Pin{};
+    /// # let g2 = Pin{};
+    /// # let b2 = Pin{};
+    /// #
+    /// use led::Led;
+    /// use led::rgb::{Color, CommonAnodeLed, Polarity};
+    ///
+    /// // `leds[1]` is actually wired common-cathode, but `with_polarity` lets it share
+    /// // `leds[0]`'s concrete type instead of needing `&mut dyn Rgb`.
+    /// let mut leds: [CommonAnodeLed<Pin, Pin, Pin>; 2] = [
+    ///     CommonAnodeLed::new(r1, g1, b1),
+    ///     CommonAnodeLed::with_polarity(r2, g2, b2, Polarity::ActiveHigh),
+    /// ];
+    ///
+    /// for led in leds.iter_mut() {
+    ///     led.set(Color::Red).unwrap();
+    /// }
+    /// ```
+    pub fn with_polarity(red: R, green: G, blue: B, polarity: Polarity) -> Led<C, R, G, B> {
+        Led {
+            common: PhantomData,
+            polarity: Some(polarity),
             red,
             green,
             blue,
@@ -157,139 +266,278 @@ where
     }
 }
 
-impl<C, R, G, B> crate::Led for Led<C, R, G, B>
+impl<C, R, G, B, E> crate::Led for Led<C, R, G, B>
 where
     C: Common,
-    R: OutputPin,
-    G: OutputPin,
-    B: OutputPin,
+    R: OutputPin<Error = E>,
+    G: OutputPin<Error = E>,
+    B: OutputPin<Error = E>,
 {
     type State = Color;
+    type Error = E;
 
     /// Sets the RGB LED to the specified color.
-    fn set(&mut self, color: Color) {
-        match color {
-            Color::Red => {
-                C::enable(&mut self.red);
-                C::disable(&mut self.green);
-                C::disable(&mut self.blue);
-            }
-            Color::Green => {
-                C::disable(&mut self.red);
-                C::enable(&mut self.green);
-                C::disable(&mut self.blue);
-            }
-            Color::Blue => {
-                C::disable(&mut self.red);
-                C::disable(&mut self.green);
-                C::enable(&mut self.blue);
-            }
-            Color::Yellow => {
-                C::enable(&mut self.red);
-                C::enable(&mut self.green);
-                C::disable(&mut self.blue);
-            }
-            Color::Cyan => {
-                C::disable(&mut self.red);
-                C::enable(&mut self.green);
-                C::enable(&mut self.blue);
-            }
-            Color::Magenta => {
-                C::enable(&mut self.red);
-                C::disable(&mut self.green);
-                C::enable(&mut self.blue);
-            }
-            Color::White => {
-                C::enable(&mut self.red);
-                C::enable(&mut self.green);
-                C::enable(&mut self.blue);
-            }
-            Color::Black => {
-                C::disable(&mut self.red);
-                C::disable(&mut self.green);
-                C::disable(&mut self.blue);
-            }
-        }
+    ///
+    /// Each channel is driven on if its intensity is at least half of full scale, so arbitrary
+    /// [`Color::Rgb`] and [`Color::Hex`] values are approximated by the nearest of the eight
+    /// colors this binary-output LED can actually display.
+    fn set(&mut self, color: Color) -> Result<(), E> {
+        let (red, green, blue) = color.split();
+        set_channel::<C, _>(&self.polarity, &mut self.red, red)?;
+        set_channel::<C, _>(&self.polarity, &mut self.green, green)?;
+        set_channel::<C, _>(&self.polarity, &mut self.blue, blue)?;
+        Ok(())
     }
 }
 
-/// The polarity of the LED; either anode or cathode.
-pub trait Common {
-    /// Enables the pin output.
-    fn enable<P: OutputPin>(pin: &mut P);
+fn set_channel<C, P>(
+    polarity: &Option<Polarity>,
+    pin: &mut P,
+    intensity: u8,
+) -> Result<(), P::Error>
+where
+    C: crate::polarity::Common,
+    P: OutputPin,
+{
+    let on = intensity >= 128;
+    match (polarity, on) {
+        (Some(polarity), true) => polarity.enable(pin),
+        (Some(polarity), false) => polarity.disable(pin),
+        (None, true) => C::enable(pin),
+        (None, false) => C::disable(pin),
+    }
+}
 
-    /// Disables the pin output.
-    fn disable<P: OutputPin>(pin: &mut P);
+/// The polarity of an LED's wiring, chosen at runtime; see [`Led::with_polarity`].
+pub enum Polarity {
+    /// The LED turns on when its pin is driven low.
+    ActiveLow,
+    /// The LED turns on when its pin is driven high.
+    ActiveHigh,
 }
 
-/// A marker type that represents a common anode connection.
-///
-/// # Examples
-///
-/// ```
-/// # use embedded_hal::digital::OutputPin;
-/// #
-/// # struct Pin {}
-/// #
-/// # impl OutputPin for Pin {
-/// #     fn set_low(&mut self) {}
-/// #     fn set_high(&mut self) {}
-/// # }
-/// #
-/// # let r = Pin{};
-/// # let g = Pin{};
-/// # let b = Pin{};
-/// #
-/// use led::rgb::{CommonAnode, Led};
+impl Polarity {
+    /// Drives the pin to turn the LED on.
+    fn enable<P: OutputPin>(&self, pin: &mut P) -> Result<(), P::Error> {
+        match self {
+            Polarity::ActiveLow => pin.set_low(),
+            Polarity::ActiveHigh => pin.set_high(),
+        }
+    }
+
+    /// Drives the pin to turn the LED off.
+    fn disable<P: OutputPin>(&self, pin: &mut P) -> Result<(), P::Error> {
+        match self {
+            Polarity::ActiveLow => pin.set_high(),
+            Polarity::ActiveHigh => pin.set_low(),
+        }
+    }
+}
+
+/// Polarity extended with duty-cycle scaling for [`PwmLed`].
 ///
-/// let led: Led<CommonAnode, _, _, _> = Led::new(r, g, b);
-/// ```
-pub struct CommonAnode {
-    private: PhantomData<()>,
+/// Besides knowing whether "on" means driving a pin low or high ([`crate::polarity::Common`]),
+/// PWM output also needs to know which direction full duty cycle runs, since common-anode wiring
+/// inverts brightness relative to the raw duty register.
+pub trait Common: crate::polarity::Common {
+    /// Scales a duty cycle for this polarity, inverting it for common-anode wiring so that a
+    /// higher `duty` always produces a higher effective brightness.
+    fn scale_duty(duty: u16, max_duty: u16) -> u16;
+}
+
+impl Common for CommonAnode {
+    fn scale_duty(duty: u16, max_duty: u16) -> u16 {
+        max_duty - duty
+    }
 }
 
-/// A marker type that represents a common cathode connection.
+impl Common for CommonCathode {
+    fn scale_duty(duty: u16, _max_duty: u16) -> u16 {
+        duty
+    }
+}
+
+/// A 256-entry gamma-correction lookup table (gamma ≈ 2.2) mapping an 8-bit linear intensity to
+/// a 16-bit perceptually-corrected value, since human brightness perception is nonlinear and a
+/// linear PWM duty cycle makes mid-range colors look washed out.
+#[rustfmt::skip]
+const GAMMA: [u16; 256] = [
+    0, 0, 2, 4, 7, 11, 17, 24,
+    32, 42, 53, 65, 79, 94, 111, 129,
+    148, 169, 192, 216, 242, 270, 299, 330,
+    362, 396, 432, 469, 508, 549, 591, 635,
+    681, 729, 779, 830, 883, 938, 995, 1053,
+    1113, 1175, 1239, 1305, 1373, 1443, 1514, 1587,
+    1663, 1740, 1819, 1900, 1983, 2068, 2155, 2243,
+    2334, 2427, 2521, 2618, 2717, 2817, 2920, 3024,
+    3131, 3240, 3350, 3463, 3578, 3694, 3813, 3934,
+    4057, 4182, 4309, 4438, 4570, 4703, 4838, 4976,
+    5115, 5257, 5401, 5547, 5695, 5845, 5998, 6152,
+    6309, 6468, 6629, 6792, 6957, 7124, 7294, 7466,
+    7640, 7816, 7994, 8175, 8358, 8543, 8730, 8919,
+    9111, 9305, 9501, 9699, 9900, 10102, 10307, 10515,
+    10724, 10936, 11150, 11366, 11585, 11806, 12029, 12254,
+    12482, 12712, 12944, 13179, 13416, 13655, 13896, 14140,
+    14386, 14635, 14885, 15138, 15394, 15652, 15912, 16174,
+    16439, 16706, 16975, 17247, 17521, 17798, 18077, 18358,
+    18642, 18928, 19216, 19507, 19800, 20095, 20393, 20694,
+    20996, 21301, 21609, 21919, 22231, 22546, 22863, 23182,
+    23504, 23829, 24156, 24485, 24817, 25151, 25487, 25826,
+    26168, 26512, 26858, 27207, 27558, 27912, 28268, 28627,
+    28988, 29351, 29717, 30086, 30457, 30830, 31206, 31585,
+    31966, 32349, 32735, 33124, 33514, 33908, 34304, 34702,
+    35103, 35507, 35913, 36321, 36732, 37146, 37562, 37981,
+    38402, 38825, 39252, 39680, 40112, 40546, 40982, 41421,
+    41862, 42306, 42753, 43202, 43654, 44108, 44565, 45025,
+    45487, 45951, 46418, 46888, 47360, 47835, 48313, 48793,
+    49275, 49761, 50249, 50739, 51232, 51728, 52226, 52727,
+    53230, 53736, 54245, 54756, 55270, 55787, 56306, 56828,
+    57352, 57879, 58409, 58941, 59476, 60014, 60554, 61097,
+    61642, 62190, 62741, 63295, 63851, 64410, 64971, 65535,
+];
+
+/// A common anode PWM-backed RGB LED.
+pub type CommonAnodePwmLed<R, G, B> = PwmLed<CommonAnode, R, G, B>;
+
+/// A common cathode PWM-backed RGB LED.
+pub type CommonCathodePwmLed<R, G, B> = PwmLed<CommonCathode, R, G, B>;
+
+/// A PWM-backed RGB LED.
+///
+/// Unlike [`Led`], which can only drive its three `OutputPin`s on or off, `PwmLed` is generic
+/// over three `embedded_hal::PwmPin` channels, so it can fade and mix the full 24-bit [`Color`]
+/// space rather than being limited to eight on/off combinations.
+///
+/// `PwmLed` also implements [`crate::Brightness`]: it remembers the last [`Color`] passed to
+/// [`Led::set`](crate::Led::set) and the last brightness, and re-renders the scaled combination
+/// of the two to the pins whenever either changes.
 ///
 /// # Examples
 ///
 /// ```
-/// # use embedded_hal::digital::OutputPin;
+/// # use embedded_hal::PwmPin;
 /// #
-/// # struct Pin {}
+/// # struct Pwm {
+/// #     duty: u16,
+/// # }
 /// #
-/// # impl OutputPin for Pin {
-/// #     fn set_low(&mut self) {}
-/// #     fn set_high(&mut self) {}
+/// # impl PwmPin for Pwm {
+/// #     type Duty = u16;
+/// #
+/// #     fn disable(&mut self) {}
+/// #     fn enable(&mut self) {}
+/// #     fn get_duty(&self) -> u16 {
+/// #         self.duty
+/// #     }
+/// #     fn get_max_duty(&self) -> u16 {
+/// #         0xFFFF
+/// #     }
+/// #     fn set_duty(&mut self, duty: u16) {
+/// #         self.duty = duty;
+/// #     }
 /// # }
 /// #
-/// # let r = Pin{};
-/// # let g = Pin{};
-/// # let b = Pin{};
+/// # let r = Pwm { duty: 0 };
+/// # let g = Pwm { duty: 0 };
+/// # let b = Pwm { duty: 0 };
 /// #
-/// use led::rgb::{CommonCathode, Led};
+/// use led::Brightness;
+/// use led::Led;
+/// use led::rgb::{Color, CommonAnodePwmLed};
+///
+/// let mut led = CommonAnodePwmLed::new(r, g, b);
 ///
-/// let led: Led<CommonCathode, _, _, _> = Led::new(r, g, b);
+/// led.set(Color::Hex(0xFF8000)).unwrap();
+/// led.set(Color::Rgb(255, 128, 0)).unwrap();
+/// led.set_brightness(128).unwrap();
 /// ```
-pub struct CommonCathode {
-    private: PhantomData<()>,
+pub struct PwmLed<C, R, G, B> {
+    common: PhantomData<C>,
+    color: Color,
+    brightness: u8,
+    red: R,
+    green: G,
+    blue: B,
 }
 
-impl Common for CommonAnode {
-    fn enable<P: OutputPin>(pin: &mut P) {
-        pin.set_low();
+impl<C, R, G, B> PwmLed<C, R, G, B>
+where
+    C: Common,
+    R: PwmPin<Duty = u16>,
+    G: PwmPin<Duty = u16>,
+    B: PwmPin<Duty = u16>,
+{
+    /// Creates a new PWM-backed RGB LED given three PWM channels, initially off at full
+    /// brightness.
+    pub fn new(red: R, green: G, blue: B) -> PwmLed<C, R, G, B> {
+        let mut led = PwmLed {
+            common: PhantomData,
+            color: Color::Black,
+            brightness: 255,
+            red,
+            green,
+            blue,
+        };
+        led.render();
+        led
     }
 
-    fn disable<P: OutputPin>(pin: &mut P) {
-        pin.set_high();
+    /// Gamma-corrects and scales the current color by the current brightness, then drives each
+    /// channel's duty cycle accordingly.
+    fn render(&mut self) {
+        let (red, green, blue) = self.color.scale(self.brightness).split();
+        set_pwm_channel::<C, _>(&mut self.red, red);
+        set_pwm_channel::<C, _>(&mut self.green, green);
+        set_pwm_channel::<C, _>(&mut self.blue, blue);
     }
 }
 
-impl Common for CommonCathode {
-    fn enable<P: OutputPin>(pin: &mut P) {
-        pin.set_high();
+impl<C, R, G, B> crate::Led for PwmLed<C, R, G, B>
+where
+    C: Common,
+    R: PwmPin<Duty = u16>,
+    G: PwmPin<Duty = u16>,
+    B: PwmPin<Duty = u16>,
+{
+    type State = Color;
+    type Error = core::convert::Infallible;
+
+    /// Sets the RGB LED to the specified color, gamma-correcting and scaling each channel's
+    /// intensity to its pin's duty cycle range at the current brightness.
+    fn set(&mut self, color: Color) -> Result<(), Self::Error> {
+        self.color = color;
+        self.render();
+        Ok(())
+    }
+}
+
+impl<C, R, G, B> crate::Brightness for PwmLed<C, R, G, B>
+where
+    C: Common,
+    R: PwmPin<Duty = u16>,
+    G: PwmPin<Duty = u16>,
+    B: PwmPin<Duty = u16>,
+{
+    type Error = core::convert::Infallible;
+
+    /// Rescales the current color to `brightness` and drives the pins with the result.
+    fn set_brightness(&mut self, brightness: u8) -> Result<(), Self::Error> {
+        self.brightness = brightness;
+        self.render();
+        Ok(())
     }
 
-    fn disable<P: OutputPin>(pin: &mut P) {
-        pin.set_low();
+    fn get_brightness(&self) -> u8 {
+        self.brightness
     }
 }
+
+fn set_pwm_channel<C, P>(pin: &mut P, intensity: u8)
+where
+    C: Common,
+    P: PwmPin<Duty = u16>,
+{
+    let max_duty = pin.get_max_duty();
+    let duty = (u32::from(GAMMA[intensity as usize]) * u32::from(max_duty) / 0xFFFF) as u16;
+    pin.set_duty(C::scale_duty(duty, max_duty));
+}